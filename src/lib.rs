@@ -1,5 +1,7 @@
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter, LowerHex};
-use std::ops::{Index, IndexMut, Range, RangeInclusive};
+use std::hash::{Hash, Hasher};
+use std::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
 
 
 fn bit(b: bool) -> usize { if b { 1 } else { 0 } }
@@ -25,19 +27,48 @@ pub enum BitsError {
     BitWidthMismatch { expected: usize, found: usize },
     /// The provided bit number is outside the bounds of this value.
     BitIndexOutOfRange,
+    /// Division or remainder was attempted with a zero divisor.
+    DivisionByZero,
 }
 
 
 /// The heart of the `bitmath` crate. `Bits` is an generically-sized bit vector,
 /// with support for accurate bitwise arithmetic including overflows and handling
 /// signed vs unsigned arguments and two's-complement conversions.
-#[derive(Debug, Copy, Clone)]
-pub struct Bits<const SIZE: usize>(pub [bool; SIZE]);
+///
+/// Internally the bits are packed into 32-bit words rather than stored one bit
+/// per byte, cutting memory use 8x and letting word-wise ops (bitwise logic,
+/// addition, shifts) scale with `SIZE / 32` rather than `SIZE`. The public API
+/// still addresses bits one at a time in "regular" MSB-first order via
+/// [`get_bit`](Self::get_bit)/[`set_bit`](Self::set_bit) (direct `[bool]`-style
+/// indexing was removed since individual packed bits can no longer be borrowed
+/// by reference). Note that `Bits` is no longer `Copy` now that it owns a
+/// `Vec`; clone it explicitly where a copy used to happen implicitly.
+#[derive(Debug, Clone)]
+pub struct Bits<const SIZE: usize>(pub Vec<u32>);
 
 impl<const SIZE: usize> Bits<SIZE> {
+    /// Number of 32-bit words needed to hold `SIZE` bits.
+    fn blocks() -> usize {
+        if SIZE.is_multiple_of(32) { SIZE / 32 } else { SIZE / 32 + 1 }
+    }
+
+    /// Mask for the valid bits of the most-significant (last) word. Re-applying
+    /// this after every mutation keeps unused high bits at zero, which
+    /// `count_ones`, equality, and value conversions all rely on.
+    fn final_word_mask() -> u32 {
+        !0u32 >> ((32 - SIZE % 32) % 32)
+    }
+
+    /// Clears the unused high bits of the most-significant word.
+    fn mask_final_word(&mut self) {
+        let last = self.0.len() - 1;
+        self.0[last] &= Self::final_word_mask();
+    }
+
     /// Create a new `Bits` with the given size, initialized to zero.
     pub fn new() -> Self {
-        Bits([false; SIZE])
+        Bits(vec![0u32; Self::blocks()])
     }
 
     /// Create a new `Bits` by parsing the provided signed integer.
@@ -46,22 +77,22 @@ impl<const SIZE: usize> Bits<SIZE> {
     /// fewer bits than an `i32` then the given value is truncated to fit. If the `Bits`
     /// constructed has more bits than an `i32` then the given value is sign-extended to match.
     pub fn from_signed(x: i32) -> Self {
-        let mut bits = Vec::new();
+        let mut bits = Bits::new();
         if SIZE <= 32 {
             for i in 0..SIZE {
-                bits.push(((x >> (SIZE-1 - i)) & 1) != 0);
+                bits.set_bit(i, ((x >> (SIZE-1 - i)) & 1) != 0).unwrap();
             }
         }
         else {
             let extend_bits = SIZE - 32;
-            for _ in 0..extend_bits {
-                bits.push(if x < 0 { true } else { false });
+            for i in 0..extend_bits {
+                bits.set_bit(i, x < 0).unwrap();
             }
             for i in 0..32 {
-                bits.push(((x >> (31 - i)) & 1) != 0);
+                bits.set_bit(extend_bits + i, ((x >> (31 - i)) & 1) != 0).unwrap();
             }
         }
-        Bits(bits.try_into().unwrap())
+        bits
     }
 
 
@@ -71,22 +102,22 @@ impl<const SIZE: usize> Bits<SIZE> {
     /// fewer bits than a `u32` then the given value is truncated to fit. If the `Bits`
     /// constructed has more bits than a `u32` then the given value is padded with zeros to match.
     pub fn from_unsigned(x: u32) -> Self {
-        let mut bits = Vec::new();
+        let mut bits = Bits::new();
         if SIZE <= 32 {
             for i in 0..SIZE {
-                bits.push(((x >> (SIZE-1 - i)) & 1) != 0);
+                bits.set_bit(i, ((x >> (SIZE-1 - i)) & 1) != 0).unwrap();
             }
         }
         else {
             let extend_bits = SIZE - 32;
-            for _ in 0..extend_bits {
-                bits.push(false);
+            for i in 0..extend_bits {
+                bits.set_bit(i, false).unwrap();
             }
             for i in 0..32 {
-                bits.push(((x >> (31 - i)) & 1) != 0);
+                bits.set_bit(extend_bits + i, ((x >> (31 - i)) & 1) != 0).unwrap();
             }
         }
-        Bits(bits.try_into().unwrap())
+        bits
     }
 
     /// Create a new `Bits` from the given slice.
@@ -96,11 +127,11 @@ impl<const SIZE: usize> Bits<SIZE> {
         if slice.len() != SIZE {
             return Err(BitsError::BitWidthMismatch { expected: SIZE, found: slice.len() });
         }
-        let mut copied = [false; SIZE];
+        let mut bits = Bits::new();
         for i in 0..SIZE {
-            copied[i] = slice[i];
+            bits.set_bit(i, slice[i]).unwrap();
         }
-        Ok(Bits(copied))
+        Ok(bits)
     }
 
     #[doc(hidden)]
@@ -115,28 +146,153 @@ impl<const SIZE: usize> Bits<SIZE> {
         if width != SIZE {
             return Err(BitsError::BitWidthMismatch{ expected: SIZE, found: width});
         }
-        let mut copied = [false; SIZE];
+        let mut bits = Bits::new();
         for i in 0..SIZE {
-            copied[i] = slice[slice.len() - high - 1 + i];
+            bits.set_bit(i, slice[slice.len() - high - 1 + i]).unwrap();
         }
-        Ok(Bits(copied))
+        Ok(bits)
     }
 
     /// Returns the width of the `Bits` in bits.
     pub const fn size(&self) -> usize { SIZE }
 
-    /// Gets an immutable reference to bit `n`, or `None` if `n` is out of bounds.
+    /// Gets the value of bit `n`, or `None` if `n` is out of bounds.
     ///
     /// Note that this function indexes in "regular" order, i.e. get_bit(0)
     /// returns the leftmost, most significant bit.
-    pub fn get_bit(&self, n: usize) -> Option<&bool> { self.0.get(n) }
-
+    pub fn get_bit(&self, n: usize) -> Option<bool> {
+        if n >= SIZE {
+            return None;
+        }
+        let pos = SIZE - 1 - n;
+        Some((self.0[pos / 32] >> (pos % 32)) & 1 != 0)
+    }
 
-    /// Gets a mutable reference to bit `n`, or `None` if `n` is out of bounds.
+    /// Sets bit `n` to `value`, or returns `Err(BitsError::BitIndexOutOfRange)`
+    /// if `n` is out of bounds.
     ///
-    /// Note that this function indexes in "regular" order, i.e. get_bit_mut(0)
-    /// returns the leftmost, most significant bit.
-    pub fn get_bit_mut(&mut self, n: usize) -> Option<&mut bool> { self.0.get_mut(n) }
+    /// Note that this function indexes in "regular" order, i.e. set_bit(0, ..)
+    /// sets the leftmost, most significant bit.
+    pub fn set_bit(&mut self, n: usize, value: bool) -> Result<(), BitsError> {
+        if n >= SIZE {
+            return Err(BitsError::BitIndexOutOfRange);
+        }
+        let pos = SIZE - 1 - n;
+        let word = pos / 32;
+        let mask = 1u32 << (pos % 32);
+        if value {
+            self.0[word] |= mask;
+        } else {
+            self.0[word] &= !mask;
+        }
+        Ok(())
+    }
+
+    /// Expands the bit vector into a `Vec<bool>` in "regular", MSB-first order.
+    pub fn to_bool_vec(&self) -> Vec<bool> {
+        (0..SIZE).map(|i| self.get_bit(i).unwrap()).collect()
+    }
+
+    /// Shared implementation for the `to_*_bytes`/`from_*_bytes` family: maps
+    /// byte `k`'s bit `b` (LSB-first within the byte, as usual) to bit position
+    /// `k*8 + b` counted up from the least-significant bit of the value. For
+    /// little-endian, byte `k` of the value lands at `buf[k]`/`bytes[k]`; for
+    /// big-endian the byte order is reversed, so byte `k` of the value lands at
+    /// the `k`-th byte from the end of the buffer.
+    fn to_bytes_generic(&self, buf: &mut [u8], big_endian: bool) {
+        for byte_idx in 0..buf.len() {
+            let mut byte = 0u8;
+            for bit_in_byte in 0..8 {
+                let position = byte_idx * 8 + bit_in_byte;
+                if position >= SIZE {
+                    continue;
+                }
+                if self.get_bit(SIZE - 1 - position).unwrap() {
+                    byte |= 1 << bit_in_byte;
+                }
+            }
+            let out_idx = if big_endian { buf.len() - 1 - byte_idx } else { byte_idx };
+            buf[out_idx] = byte;
+        }
+    }
+
+    fn from_bytes_generic(bytes: &[u8], big_endian: bool) -> Self {
+        let mut result = Bits::new();
+        for byte_idx in 0..bytes.len() {
+            let position_base = byte_idx * 8;
+            if position_base >= SIZE {
+                break;
+            }
+            let in_idx = if big_endian { bytes.len() - 1 - byte_idx } else { byte_idx };
+            let byte = bytes[in_idx];
+            for bit_in_byte in 0..8 {
+                let position = position_base + bit_in_byte;
+                if position >= SIZE {
+                    continue;
+                }
+                result.set_bit(SIZE - 1 - position, (byte >> bit_in_byte) & 1 != 0).unwrap();
+            }
+        }
+        result
+    }
+
+    /// Parses a little-endian byte slice into a `Bits`. If `bytes` has more bits
+    /// than `SIZE`, the extra high-order bytes are ignored; if it has fewer, the
+    /// remaining high bits are zero-filled.
+    pub fn from_le_bytes(bytes: &[u8]) -> Self {
+        Self::from_bytes_generic(bytes, false)
+    }
+
+    /// Writes this value into `buf` in little-endian byte order. If `buf` is
+    /// shorter than `SIZE` bits, the high-order bits that don't fit are dropped;
+    /// if it's longer, the remaining high-order bytes are zero-filled.
+    pub fn to_le_bytes(&self, buf: &mut [u8]) {
+        self.to_bytes_generic(buf, false)
+    }
+
+    /// Parses a big-endian (most-significant-byte-first) byte slice into a
+    /// `Bits`. If `bytes` has more bits than `SIZE`, the extra high-order bytes
+    /// are ignored; if it has fewer, the remaining high bits are zero-filled.
+    pub fn from_be_bytes(bytes: &[u8]) -> Self {
+        Self::from_bytes_generic(bytes, true)
+    }
+
+    /// Writes this value into `buf` in big-endian (most-significant-byte-first)
+    /// byte order. If `buf` is shorter than `SIZE` bits, the high-order bits
+    /// that don't fit are dropped; if it's longer, the remaining high-order
+    /// bytes are zero-filled.
+    pub fn to_be_bytes(&self, buf: &mut [u8]) {
+        self.to_bytes_generic(buf, true)
+    }
+
+    /// Resizes to a `Bits` of a different width `M`, padding new high bits with
+    /// zero when growing and truncating the high bits when shrinking.
+    pub fn zero_resize<const M: usize>(&self) -> Bits<M> {
+        let mut result: Bits<M> = Bits::new();
+        let copy_len = M.min(SIZE);
+        for i in 0..copy_len {
+            result.set_bit(M - 1 - i, self.get_bit(SIZE - 1 - i).unwrap()).unwrap();
+        }
+        result
+    }
+
+    /// Resizes to a `Bits` of a different width `M`, replicating the sign bit
+    /// (index `0`) into new high bits when growing, and truncating the high
+    /// bits when shrinking.
+    pub fn sign_resize<const M: usize>(&self) -> Bits<M> {
+        let sign = self.get_bit(0).unwrap();
+        let mut result: Bits<M> = Bits::new();
+        if sign {
+            for i in 0..M {
+                result.set_bit(i, true).unwrap();
+            }
+        }
+        let copy_len = M.min(SIZE);
+        for i in 0..copy_len {
+            result.set_bit(M - 1 - i, self.get_bit(SIZE - 1 - i).unwrap()).unwrap();
+        }
+        result
+    }
 
     /// Converts the bit vector into an unsigned integer value.
     pub fn unsigned_value(&self) -> u32 {
@@ -144,7 +300,7 @@ impl<const SIZE: usize> Bits<SIZE> {
         let start_idx = (SIZE as i32 - 32).max(0) as usize;
         for i in 0..self.size().min(32) {
             result <<= 1;
-            result |= bit(self.0[start_idx+i]) as u32;
+            result |= bit(self.get_bit(start_idx+i).unwrap()) as u32;
         }
         result
     }
@@ -154,47 +310,263 @@ impl<const SIZE: usize> Bits<SIZE> {
         let mut result = 0u32;
         let start_idx = (SIZE as i32 - 32).max(0) as usize;
         let extend_bits = (32 - SIZE as i32).max(0) as usize;
-        let is_negative = self.0[0] == true;
+        let is_negative = self.get_bit(0).unwrap();
         for _ in 0..extend_bits {
             result <<= 1;
             result |= if is_negative { 1 } else { 0 };
         }
         for i in 0..SIZE.min(32) {
             result <<= 1;
-            result |= *self.get_bit(start_idx+i).unwrap() as u32;
+            result |= self.get_bit(start_idx+i).unwrap() as u32;
         }
         unsafe { std::mem::transmute(result) }
     }
 
     /// Performs an unsigned addition between this and `other`, returning the result
     /// as a new `Bits`, as well as whether or not an overflow occurred.
+    ///
+    /// This ripples a carry word-at-a-time from the least-significant word up to
+    /// the most-significant, so it works natively at any `SIZE` instead of
+    /// bouncing through a fixed-width integer.
     pub fn unsigned_add(&self, other: Self) -> (Self, bool) {
-        let a = self.unsigned_value() as u64;
-        let b = other.unsigned_value() as u64;
-        let sum = a + b;
-        let mut mask = 1u64;
-        for _ in 0..SIZE-1 {
-            mask <<= 1;
-            mask |= 1;
+        let blocks = Self::blocks();
+        let mut result = Bits::new();
+        let mut carry: u64 = 0;
+        for i in 0..blocks {
+            let sum = self.0[i] as u64 + other.0[i] as u64 + carry;
+            result.0[i] = sum as u32;
+            carry = sum >> 32;
+        }
+        let mask = Self::final_word_mask();
+        let last = blocks - 1;
+        let overflow = carry != 0 || (result.0[last] & !mask) != 0;
+        result.0[last] &= mask;
+        (result, overflow)
+    }
+
+    /// Performs a two's-complement subtraction of `other` from this, returning the
+    /// result as a new `Bits`, as well as whether or not a borrow occurred.
+    ///
+    /// Computed word-at-a-time as `self + (!other) + 1`, seeding the carry-in
+    /// with the `+ 1`. The absence of a carry out of the most-significant valid
+    /// bit means a borrow was needed.
+    pub fn subtract(&self, other: Self) -> (Self, bool) {
+        let blocks = Self::blocks();
+        let mask = Self::final_word_mask();
+        let last = blocks - 1;
+        let mut result = Bits::new();
+        let mut carry: u64 = 1;
+        for i in 0..blocks {
+            let mut b_inv = !other.0[i];
+            if i == last {
+                b_inv &= mask;
+            }
+            let sum = self.0[i] as u64 + b_inv as u64 + carry;
+            result.0[i] = sum as u32;
+            carry = sum >> 32;
         }
-        let result = (sum & mask) as u32;
-        (Bits::from_unsigned(result), (sum >> SIZE) > 0)
+        let overflow_bits = result.0[last] & !mask;
+        result.0[last] &= mask;
+        (result, !(carry != 0 || overflow_bits != 0))
     }
 
     /// Performs a signed addition between this and `other`, returning the result
     /// as a new `Bits`, as well as whether or not an overflow occurred.
+    ///
+    /// Two's-complement addition uses the same bit pattern as unsigned addition,
+    /// so this reuses [`unsigned_add`](Self::unsigned_add) and detects overflow the
+    /// standard way: it occurred iff both operands share a sign bit that differs
+    /// from the result's sign bit.
     pub fn signed_add(&self, other: Self) -> (Self, bool) {
-        let a = self.signed_value() as i64;
-        let b = other.signed_value() as i64;
-        let sum = a + b;
-        let mut mask = 1i64;
-        for _ in 0..SIZE-1 {
-            mask <<= 1;
-            mask |= 1;
+        let (result, _) = self.unsigned_add(other.clone());
+        let a_sign = self.get_bit(0).unwrap();
+        let b_sign = other.get_bit(0).unwrap();
+        let r_sign = result.get_bit(0).unwrap();
+        let overflow = a_sign == b_sign && r_sign != a_sign;
+        (result, overflow)
+    }
+
+    /// Shifts the bits left by `n`, zero-filling the vacated low bits. `n` may
+    /// exceed `SIZE`, in which case the result is all-zero.
+    fn shl_n(&self, n: usize) -> Self {
+        let blocks = Self::blocks();
+        let mut result = Bits::new();
+        if n >= SIZE {
+            return result;
+        }
+        let word_shift = n / 32;
+        let bit_shift = n % 32;
+        for i in (0..blocks).rev() {
+            if i < word_shift {
+                continue;
+            }
+            let src = i - word_shift;
+            let mut word = self.0[src] << bit_shift;
+            if bit_shift > 0 && src > 0 {
+                word |= self.0[src - 1] >> (32 - bit_shift);
+            }
+            result.0[i] = word;
+        }
+        result.mask_final_word();
+        result
+    }
+
+    /// Shifts the bits right by `n`, zero-filling the vacated high bits. `n` may
+    /// exceed `SIZE`, in which case the result is all-zero.
+    pub fn shr_logical(&self, n: usize) -> Self {
+        let blocks = Self::blocks();
+        let mut result = Bits::new();
+        if n >= SIZE {
+            return result;
+        }
+        let word_shift = n / 32;
+        let bit_shift = n % 32;
+        for i in 0..blocks {
+            let src = i + word_shift;
+            if src >= blocks {
+                continue;
+            }
+            let mut word = self.0[src] >> bit_shift;
+            if bit_shift > 0 && src + 1 < blocks {
+                word |= self.0[src + 1] << (32 - bit_shift);
+            }
+            result.0[i] = word;
+        }
+        result.mask_final_word();
+        result
+    }
+
+    /// Shifts the bits right by `n`, replicating the sign bit (index `0`) into
+    /// the vacated high bits. `n` may exceed `SIZE`, in which case the result is
+    /// all-sign.
+    pub fn shr_arithmetic(&self, n: usize) -> Self {
+        let sign = self.get_bit(0).unwrap();
+        let mut result = self.shr_logical(n);
+        if sign {
+            for i in 0..n.min(SIZE) {
+                result.set_bit(i, true).unwrap();
+            }
+        }
+        result
+    }
+
+    /// Two's-complement negation (`0 - self`).
+    fn negate(&self) -> Self {
+        Bits::new().subtract(self.clone()).0
+    }
+
+    /// Compares two same-width `Bits` as unsigned magnitudes, from the
+    /// most-significant word downward.
+    fn unsigned_ge(&self, other: &Self) -> bool {
+        for i in (0..Self::blocks()).rev() {
+            if self.0[i] != other.0[i] {
+                return self.0[i] > other.0[i];
+            }
+        }
+        true
+    }
+
+    /// Compares two `Bits` of the same width as two's-complement (signed)
+    /// values, rather than the unsigned-magnitude ordering `Ord` provides.
+    pub fn signed_cmp(&self, other: &Self) -> Ordering {
+        let a_sign = self.get_bit(0).unwrap();
+        let b_sign = other.get_bit(0).unwrap();
+        if a_sign != b_sign {
+            return if a_sign { Ordering::Less } else { Ordering::Greater };
+        }
+        self.cmp(other)
+    }
+
+    /// Performs an unsigned multiplication between this and `other`, returning
+    /// the low `SIZE` bits of the result as well as whether any bits were lost
+    /// to overflow.
+    ///
+    /// Uses schoolbook shift-and-add: for each set bit of the multiplier, the
+    /// multiplicand shifted left by that bit's position is added into the
+    /// accumulator.
+    pub fn unsigned_mul(&self, other: Self) -> (Self, bool) {
+        let mut acc = Bits::new();
+        let mut overflow = false;
+        for j in 0..SIZE {
+            let bit_pos = SIZE - 1 - j;
+            if other.get_bit(bit_pos).unwrap() {
+                if (0..j).any(|k| self.get_bit(k).unwrap()) {
+                    overflow = true;
+                }
+                let (sum, carry) = acc.unsigned_add(self.shl_n(j));
+                acc = sum;
+                overflow |= carry;
+            }
         }
-        let result = (sum & mask) as i32;
-        let overflow = sum < -(2u64.pow(SIZE as u32 - 1) as i64) || sum > (2u64.pow(SIZE as u32 - 1) - 1) as i64;
-        (Bits::from_signed(result), overflow)
+        (acc, overflow)
+    }
+
+    /// Performs a signed multiplication between this and `other`, returning the
+    /// result as well as whether an overflow occurred.
+    ///
+    /// The operands' magnitudes are multiplied with [`unsigned_mul`](Self::unsigned_mul)
+    /// and the sign of the result is fixed up afterward (negative iff exactly one
+    /// operand was negative).
+    pub fn signed_mul(&self, other: Self) -> (Self, bool) {
+        let a_neg = self.get_bit(0).unwrap();
+        let b_neg = other.get_bit(0).unwrap();
+        let a_mag = if a_neg { self.negate() } else { self.clone() };
+        let b_mag = if b_neg { other.negate() } else { other };
+        let (mag, mag_overflow) = a_mag.unsigned_mul(b_mag);
+        let result_neg = a_neg != b_neg;
+        // the magnitude must fit in the non-sign bits, or it can't be represented
+        // as a positive two's-complement value of this width; a negative result
+        // may also take the one magnitude with only the top bit set (the most
+        // negative value)
+        let top_bit = mag.get_bit(0).unwrap();
+        let most_negative = result_neg && top_bit && mag.count_ones() == 1;
+        let overflow = mag_overflow || (top_bit && !most_negative);
+        let result = if result_neg { mag.negate() } else { mag };
+        (result, overflow)
+    }
+
+    /// Performs unsigned division between this (the dividend) and `other` (the
+    /// divisor), returning `(quotient, remainder)`, or `Err(BitsError::DivisionByZero)`
+    /// if `other` is zero.
+    ///
+    /// Uses restoring binary long division: the remainder is shifted left by one
+    /// bit and the next dividend bit (most-significant first) is brought in; if
+    /// the remainder is now `>=` the divisor, the divisor is subtracted and the
+    /// corresponding quotient bit is set.
+    pub fn unsigned_divrem(&self, other: Self) -> Result<(Self, Self), BitsError> {
+        if other.0.iter().all(|&w| w == 0) {
+            return Err(BitsError::DivisionByZero);
+        }
+        let mut quotient = Bits::new();
+        let mut remainder = Bits::new();
+        for i in 0..SIZE {
+            remainder = remainder.shl_n(1);
+            remainder.set_bit(SIZE - 1, self.get_bit(i).unwrap()).unwrap();
+            if remainder.unsigned_ge(&other) {
+                remainder = remainder.subtract(other.clone()).0;
+                quotient.set_bit(i, true).unwrap();
+            }
+        }
+        Ok((quotient, remainder))
+    }
+
+    /// Performs signed division between this (the dividend) and `other` (the
+    /// divisor), returning `(quotient, remainder)`, or `Err(BitsError::DivisionByZero)`
+    /// if `other` is zero.
+    ///
+    /// Division is performed on the operands' magnitudes via
+    /// [`unsigned_divrem`](Self::unsigned_divrem); the quotient is negative iff
+    /// the operand signs differ, and the remainder takes the dividend's sign.
+    pub fn signed_divrem(&self, other: Self) -> Result<(Self, Self), BitsError> {
+        let a_neg = self.get_bit(0).unwrap();
+        let b_neg = other.get_bit(0).unwrap();
+        let a_mag = if a_neg { self.negate() } else { self.clone() };
+        let b_mag = if b_neg { other.negate() } else { other };
+        let (q_mag, r_mag) = a_mag.unsigned_divrem(b_mag)?;
+        let q_neg = a_neg != b_neg;
+        let quotient = if q_neg { q_mag.negate() } else { q_mag };
+        let remainder = if a_neg { r_mag.negate() } else { r_mag };
+        Ok((quotient, remainder))
     }
 
     /// Rotates the bits right by `n` bits. `n` can be greater than `SIZE`,
@@ -203,7 +575,7 @@ impl<const SIZE: usize> Bits<SIZE> {
         let n = n % SIZE;
         let mut result = Bits::new();
         for i in 0..SIZE {
-            result.0[(i+n)%SIZE] = self.0[i];
+            result.set_bit((i+n)%SIZE, self.get_bit(i).unwrap()).unwrap();
         }
         result
     }
@@ -215,20 +587,86 @@ impl<const SIZE: usize> Bits<SIZE> {
         let mut result = Bits::new();
         for i in 0..SIZE {
             // conversion to signed to prevent underflow
-            result.0[(i+SIZE-n) % SIZE] = self.0[i];
+            result.set_bit((i+SIZE-n) % SIZE, self.get_bit(i).unwrap()).unwrap();
         }
         result
     }
 
+    /// Returns `true` if any bit is set (logical OR of all bits).
+    pub fn any(&self) -> bool {
+        self.0.iter().any(|&w| w != 0)
+    }
+
+    /// Returns `true` if every bit is set (logical AND of all bits).
+    pub fn all(&self) -> bool {
+        let blocks = Self::blocks();
+        self.0[..blocks-1].iter().all(|&w| w == !0u32) && self.0[blocks-1] == Self::final_word_mask()
+    }
+
+    /// Returns the parity of the bit vector: `true` when an odd number of bits
+    /// are set.
+    pub fn xor(&self) -> bool {
+        self.count_ones() % 2 == 1
+    }
+
+    /// Returns the number of set bits (popcount).
+    pub fn count_ones(&self) -> usize {
+        self.0.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns the number of unset bits.
+    pub fn count_zeros(&self) -> usize {
+        SIZE - self.count_ones()
+    }
+
+    /// Returns the number of leading zero bits, scanning from the
+    /// most-significant bit (index `0`). Returns `SIZE` if every bit is zero.
+    pub fn leading_zeros(&self) -> usize {
+        let blocks = Self::blocks();
+        let rem = SIZE % 32;
+        let last_width = if rem == 0 { 32 } else { rem };
+        let mut count;
+        let last = self.0[blocks - 1];
+        if last == 0 {
+            count = last_width;
+        } else {
+            return last.leading_zeros() as usize - (32 - last_width);
+        }
+        for i in (0..blocks - 1).rev() {
+            let w = self.0[i];
+            if w == 0 {
+                count += 32;
+            } else {
+                return count + w.leading_zeros() as usize;
+            }
+        }
+        count
+    }
+
+    /// Returns the number of trailing zero bits, scanning from the
+    /// least-significant bit (index `SIZE-1`). Returns `SIZE` if every bit is zero.
+    pub fn trailing_zeros(&self) -> usize {
+        let blocks = Self::blocks();
+        let mut count = 0;
+        for i in 0..blocks {
+            let w = self.0[i];
+            if w == 0 {
+                count += 32;
+            } else {
+                return (count + w.trailing_zeros() as usize).min(SIZE);
+            }
+        }
+        SIZE
+    }
+
     /// Produces the contents of the bit vector as a string of ones and zeros.
     ///
     /// The parameter, `pretty`, determines whether or not spaces will be added
     /// to the output string for readability.
     pub fn bits_string(&self, pretty: bool) -> String {
-        let mut bitstr: String = self.0.map(|b| if b { "1".into() } else { "0".into() })
-            .into_iter()
-            .collect::<Vec<String>>()
-            .join("");
+        let mut bitstr: String = (0..SIZE)
+            .map(|i| if self.get_bit(i).unwrap() { '1' } else { '0' })
+            .collect();
         if pretty {
             for i in 1..SIZE {
                 let idx = SIZE - i;
@@ -261,7 +699,7 @@ impl<const SIZE: usize> Bits<SIZE> {
 
 impl<const N: usize> Default for Bits<N> {
     fn default() -> Self {
-        Bits([false; N])
+        Bits::new()
     }
 }
 
@@ -287,67 +725,329 @@ impl<const N: usize> TryFrom<&str> for Bits<N> {
         if input.len() > N || input.chars().any(|c| c != '0' && c != '1') {
             return Err(BitsError::InvalidInputString);
         }
-        let mut result = Bits([false; N]);
+        let mut result = Bits::new();
         for i in 0..N {
             let c = input.chars().nth(i).unwrap();
-            result.0[i] = if c == '0' { false } else { true };
+            result.set_bit(i, c != '0').unwrap();
         }
         Ok(result)
     }
 }
 
 
-impl<const N: usize> Index<usize> for Bits<N> {
-    type Output = bool;
+impl<const N: usize> PartialEq for Bits<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+
+impl<const N: usize> Eq for Bits<N> {}
 
-    fn index(&self, index: usize) -> &Self::Output {
-        self.get_bit(index).unwrap()
+
+/// Orders `Bits` as unsigned magnitudes, comparing word-at-a-time from the
+/// most-significant word downward. This matches `unsigned_value()` for widths
+/// that fit in a `u32` and stays correct for wider `Bits`. See
+/// [`signed_cmp`](Bits::signed_cmp) for a two's-complement ordering instead.
+impl<const N: usize> Ord for Bits<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        for i in (0..self.0.len()).rev() {
+            match self.0[i].cmp(&other.0[i]) {
+                Ordering::Equal => continue,
+                ord => return ord,
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+
+impl<const N: usize> PartialOrd for Bits<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+
+impl<const N: usize> Hash for Bits<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+
+impl<const N: usize> BitAnd for Bits<N> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        let mut result = Bits::new();
+        for i in 0..self.0.len() {
+            result.0[i] = self.0[i] & rhs.0[i];
+        }
+        result
     }
 }
 
 
-impl<const N: usize> IndexMut<usize> for Bits<N> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        self.get_bit_mut(index).unwrap()
+impl<const N: usize> BitOr for Bits<N> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        let mut result = Bits::new();
+        for i in 0..self.0.len() {
+            result.0[i] = self.0[i] | rhs.0[i];
+        }
+        result
     }
 }
 
 
-impl <const N: usize> Index<Range<usize>>for Bits<N> {
-    type Output = [bool];
+impl<const N: usize> BitXor for Bits<N> {
+    type Output = Self;
 
-    fn index(&self, index: Range<usize>) -> &Self::Output {
-        &self.0[index]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        let mut result = Bits::new();
+        for i in 0..self.0.len() {
+            result.0[i] = self.0[i] ^ rhs.0[i];
+        }
+        result
     }
 }
 
 
-impl <const N: usize> Index<RangeInclusive<usize>>for Bits<N> {
-    type Output = [bool];
+impl<const N: usize> Not for Bits<N> {
+    type Output = Self;
 
-    fn index(&self, index: RangeInclusive<usize>) -> &Self::Output {
-        &self.0[index]
+    fn not(self) -> Self::Output {
+        let mut result = Bits::new();
+        for i in 0..self.0.len() {
+            result.0[i] = !self.0[i];
+        }
+        result.mask_final_word();
+        result
     }
 }
 
 
-impl <const N: usize> IndexMut<Range<usize>>for Bits<N> {
-    fn index_mut(&mut self, index: Range<usize>) -> &mut Self::Output {
-        &mut self.0[index]
+impl<const N: usize> Shl<usize> for Bits<N> {
+    type Output = Self;
+
+    fn shl(self, rhs: usize) -> Self::Output {
+        self.shl_n(rhs)
     }
 }
 
 
-impl <const N: usize> IndexMut<RangeInclusive<usize>>for Bits<N> {
-    fn index_mut(&mut self, index: RangeInclusive<usize>) -> &mut Self::Output {
-        &mut self.0[index]
+/// Defaults to a logical (zero-filling) shift. Use
+/// [`shr_arithmetic`](Bits::shr_arithmetic) directly for a sign-replicating shift.
+impl<const N: usize> Shr<usize> for Bits<N> {
+    type Output = Self;
+
+    fn shr(self, rhs: usize) -> Self::Output {
+        self.shr_logical(rhs)
     }
 }
 
+
 /// convenience macro for indexing bitwise slices using `bits[7:0]` syntax
 #[macro_export]
 macro_rules! bitslice {
     ($name:ident[$high:literal:$low:literal]) => {
-        bitmath::Bits::<{$high-$low+1}>::from_reverse_index(&$name.0,$high,$low).unwrap()
+        bitmath::Bits::<{$high-$low+1}>::from_reverse_index(&$name.to_bool_vec(),$high,$low).unwrap()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsigned_add_overflow() {
+        let a = Bits::<8>::from_unsigned(200);
+        let b = Bits::<8>::from_unsigned(100);
+        let (sum, overflow) = a.unsigned_add(b);
+        assert_eq!(sum.unsigned_value(), 44); // 300 mod 256
+        assert!(overflow);
+
+        let a = Bits::<8>::from_unsigned(10);
+        let b = Bits::<8>::from_unsigned(20);
+        let (sum, overflow) = a.unsigned_add(b);
+        assert_eq!(sum.unsigned_value(), 30);
+        assert!(!overflow);
+    }
+
+    #[test]
+    fn subtract_borrow() {
+        let a = Bits::<8>::from_unsigned(10);
+        let b = Bits::<8>::from_unsigned(20);
+        let (diff, borrow) = a.subtract(b);
+        assert_eq!(diff.unsigned_value(), 246); // wraps: 10 - 20 mod 256
+        assert!(borrow);
+
+        let a = Bits::<8>::from_unsigned(20);
+        let b = Bits::<8>::from_unsigned(10);
+        let (diff, borrow) = a.subtract(b);
+        assert_eq!(diff.unsigned_value(), 10);
+        assert!(!borrow);
+    }
+
+    #[test]
+    fn signed_add_overflow() {
+        let a = Bits::<8>::from_signed(100);
+        let b = Bits::<8>::from_signed(100);
+        let (sum, overflow) = a.signed_add(b);
+        assert!(overflow);
+        assert_eq!(sum.signed_value(), -56); // 200 wraps to -56 in i8
+
+        let a = Bits::<8>::from_signed(-5);
+        let b = Bits::<8>::from_signed(3);
+        let (sum, overflow) = a.signed_add(b);
+        assert!(!overflow);
+        assert_eq!(sum.signed_value(), -2);
+    }
+
+    #[test]
+    fn unsigned_mul_overflow() {
+        let a = Bits::<8>::from_unsigned(20);
+        let b = Bits::<8>::from_unsigned(20);
+        let (product, overflow) = a.unsigned_mul(b);
+        assert_eq!(product.unsigned_value(), 400 % 256);
+        assert!(overflow);
+
+        let a = Bits::<8>::from_unsigned(5);
+        let b = Bits::<8>::from_unsigned(5);
+        let (product, overflow) = a.unsigned_mul(b);
+        assert_eq!(product.unsigned_value(), 25);
+        assert!(!overflow);
+    }
+
+    #[test]
+    fn signed_mul_overflow() {
+        let a = Bits::<8>::from_signed(-5);
+        let b = Bits::<8>::from_signed(6);
+        let (product, overflow) = a.signed_mul(b);
+        assert_eq!(product.signed_value(), -30);
+        assert!(!overflow);
+
+        let a = Bits::<8>::from_signed(20);
+        let b = Bits::<8>::from_signed(20);
+        let (_product, overflow) = a.signed_mul(b);
+        assert!(overflow); // 400 doesn't fit in i8
+    }
+
+    #[test]
+    fn unsigned_divrem_basic_and_by_zero() {
+        let a = Bits::<8>::from_unsigned(17);
+        let b = Bits::<8>::from_unsigned(5);
+        let (q, r) = a.unsigned_divrem(b).unwrap();
+        assert_eq!(q.unsigned_value(), 3);
+        assert_eq!(r.unsigned_value(), 2);
+
+        let zero = Bits::<8>::from_unsigned(0);
+        assert!(matches!(a.unsigned_divrem(zero), Err(BitsError::DivisionByZero)));
+    }
+
+    #[test]
+    fn signed_divrem_sign_rules() {
+        let a = Bits::<8>::from_signed(-17);
+        let b = Bits::<8>::from_signed(5);
+        let (q, r) = a.signed_divrem(b).unwrap();
+        assert_eq!(q.signed_value(), -3);
+        assert_eq!(r.signed_value(), -2);
+    }
+
+    #[test]
+    fn bit_reduction_helpers() {
+        let zero = Bits::<16>::from_unsigned(0);
+        let all_ones = !zero.clone();
+        assert!(!zero.any());
+        assert!(!zero.all());
+        assert!(all_ones.any());
+        assert!(all_ones.all());
+        assert_eq!(zero.count_ones(), 0);
+        assert_eq!(all_ones.count_ones(), 16);
+        assert_eq!(all_ones.count_zeros(), 0);
+
+        let one = Bits::<16>::from_unsigned(1);
+        assert!(one.xor()); // single set bit -> odd parity
+        assert!(!all_ones.xor()); // 16 set bits -> even parity
+    }
+
+    #[test]
+    fn leading_and_trailing_zeros() {
+        let zero = Bits::<16>::from_unsigned(0);
+        assert_eq!(zero.leading_zeros(), 16);
+        assert_eq!(zero.trailing_zeros(), 16);
+
+        let val = Bits::<16>::from_unsigned(0b0000_0001_0000_0000);
+        assert_eq!(val.leading_zeros(), 7);
+        assert_eq!(val.trailing_zeros(), 8);
+    }
+
+    #[test]
+    fn bitwise_operators() {
+        let a = Bits::<8>::from_unsigned(0b1100_1010);
+        let b = Bits::<8>::from_unsigned(0b1010_1100);
+        assert_eq!((a.clone() & b.clone()).unsigned_value(), 0b1000_1000);
+        assert_eq!((a.clone() | b.clone()).unsigned_value(), 0b1110_1110);
+        assert_eq!((a.clone() ^ b.clone()).unsigned_value(), 0b0110_0110);
+        assert_eq!((!a).unsigned_value(), 0b0011_0101);
+    }
+
+    #[test]
+    fn shifts_saturate_past_width() {
+        let a = Bits::<8>::from_unsigned(0b0000_1111);
+        assert_eq!((a.clone() << 4).unsigned_value(), 0b1111_0000);
+        assert_eq!((a.clone() << 100).unsigned_value(), 0); // shift wider than SIZE
+        assert_eq!((a.clone() >> 100).unsigned_value(), 0);
+
+        let neg = Bits::<8>::from_signed(-1);
+        assert_eq!(neg.shr_arithmetic(4).unsigned_value(), 0xFF); // sign-extends
+        assert_eq!(neg.shr_arithmetic(100).unsigned_value(), 0xFF);
+        assert_eq!(neg.shr_logical(4).unsigned_value(), 0x0F); // zero-fills
+    }
+
+    #[test]
+    fn ordering_is_unsigned_and_signed_cmp_is_twos_complement() {
+        let small = Bits::<8>::from_unsigned(10);
+        let big = Bits::<8>::from_unsigned(200);
+        assert!(small < big);
+
+        // as signed values, 200u8's bit pattern is -56, which is less than 10
+        let small_signed = Bits::<8>::from_signed(10);
+        let big_bitpattern = Bits::<8>::from_unsigned(200);
+        assert_eq!(small_signed.signed_cmp(&big_bitpattern), Ordering::Greater);
+    }
+
+    #[test]
+    fn byte_round_trip_non_multiple_of_8_width() {
+        let original = Bits::<12>::from_unsigned(0b1010_1100_0101);
+        let mut buf = [0u8; 2];
+        original.to_le_bytes(&mut buf);
+        let roundtrip = Bits::<12>::from_le_bytes(&buf);
+        assert_eq!(roundtrip, original);
+
+        let mut buf = [0u8; 2];
+        original.to_be_bytes(&mut buf);
+        let roundtrip = Bits::<12>::from_be_bytes(&buf);
+        assert_eq!(roundtrip, original);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn resize_zero_and_sign_extend() {
+        let positive = Bits::<8>::from_signed(42);
+        let widened: Bits<16> = positive.zero_resize();
+        assert_eq!(widened.unsigned_value(), 42);
+
+        let negative = Bits::<8>::from_signed(-1);
+        let sign_widened: Bits<16> = negative.sign_resize();
+        assert_eq!(sign_widened.signed_value(), -1);
+
+        let zero_widened: Bits<16> = negative.zero_resize();
+        assert_eq!(zero_widened.unsigned_value(), 0x00FF);
+
+        let narrowed: Bits<4> = Bits::<8>::from_unsigned(0b1111_0110).zero_resize();
+        assert_eq!(narrowed.unsigned_value(), 0b0110);
+    }
+}